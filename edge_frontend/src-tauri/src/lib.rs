@@ -1,10 +1,11 @@
  // Tauri V2
-use log::{error, info};
+use log::{error, info, warn};
 use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
 use std::net::{SocketAddr, TcpStream};
 use std::sync::Arc;
 use std::{env, fs, path::PathBuf, sync::Mutex, time::Duration};
-use tauri::{path::BaseDirectory, AppHandle, Manager, PhysicalSize, WebviewWindow};
+use tauri::{path::BaseDirectory, AppHandle, Emitter, Manager, PhysicalSize, WebviewWindow};
 use tauri_plugin_shell::ShellExt;
 use tauri_plugin_shell::process::{CommandEvent, CommandChild};
 
@@ -146,8 +147,71 @@ impl Drop for WebSocketSidecar {
     }
 }
 
+/// Health/restart bookkeeping for the supervised sidecar, exposed to the
+/// frontend via `get_sidecar_status`.
+#[derive(Debug, Default, Clone, Serialize)]
+struct SidecarHealth {
+    running: bool,
+    restart_count: u32,
+    last_exit_code: Option<i32>,
+    /// Set once the supervisor has given up after exhausting restart attempts.
+    fatal_error: Option<String>,
+    /// PID of the process we last spawned, used to tell "our sidecar" apart
+    /// from an unrelated process that happens to be listening on the port.
+    owner_pid: Option<u32>,
+}
+
+/// Which child stream a log line came from.
+#[derive(Debug, Clone, Copy, Serialize)]
+#[serde(rename_all = "lowercase")]
+enum LogStream {
+    Stdout,
+    Stderr,
+}
+
+/// A single captured line of sidecar output, structured so the webview can
+/// render a log console instead of a wall of unstructured text.
+#[derive(Debug, Clone, Serialize)]
+struct SidecarLogLine {
+    stream: LogStream,
+    level: String,
+    message: String,
+    timestamp_ms: u64,
+}
+
+// Ring buffer size for backfilling a newly opened window
+const LOG_BUFFER_CAPACITY: usize = 500;
+
+fn now_ms() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or(0)
+}
+
 // Shared state wrapper
-struct WebSocketState(Arc<Mutex<Option<WebSocketSidecar>>>);
+#[derive(Clone)]
+struct WebSocketState {
+    sidecar: Arc<Mutex<Option<WebSocketSidecar>>>,
+    health: Arc<Mutex<SidecarHealth>>,
+    logs: Arc<Mutex<VecDeque<SidecarLogLine>>>,
+    /// Wakes the supervisor loop to restart the sidecar outside of a crash,
+    /// e.g. for dev-mode hot reload.
+    restart_signal: Arc<tokio::sync::Notify>,
+    /// Set while a hot-reload restart is in flight so a burst of file-watcher
+    /// events only triggers one restart.
+    hot_reload_in_progress: Arc<std::sync::atomic::AtomicBool>,
+    /// Set by `stop_websocket_sidecar` before it touches the child, so the
+    /// supervisor loop can tell an intentional stop apart from a crash and
+    /// return instead of respawning.
+    shutdown_requested: Arc<std::sync::atomic::AtomicBool>,
+    /// Wakes the supervisor loop as soon as shutdown is requested, instead of
+    /// waiting for it to notice the child has exited.
+    shutdown_signal: Arc<tokio::sync::Notify>,
+    /// The port actually bound by the running sidecar, which may differ from
+    /// `preferred_websocket_port()` if that one was taken by another process.
+    port: Arc<Mutex<u16>>,
+}
 
 // Function to check if a port is already in use
 fn is_port_in_use(port: u16) -> bool {
@@ -157,246 +221,807 @@ fn is_port_in_use(port: u16) -> bool {
     ).is_ok()
 }
 
+// Looks up the PID listening on `port` by enumerating the OS socket table,
+// instead of shelling out to `netstat`/`lsof`.
+fn find_pid_listening_on_port(port: u16) -> Option<u32> {
+    let af_flags = netstat2::AddressFamilyFlags::IPV4 | netstat2::AddressFamilyFlags::IPV6;
+    let proto_flags = netstat2::ProtocolFlags::TCP;
+
+    let sockets = match netstat2::get_sockets_info(af_flags, proto_flags) {
+        Ok(sockets) => sockets,
+        Err(e) => {
+            warn!("Failed to enumerate sockets: {}", e);
+            return None;
+        }
+    };
+
+    sockets.into_iter().find_map(|socket| match socket.protocol_socket_info {
+        netstat2::ProtocolSocketInfo::Tcp(tcp)
+            if tcp.local_port == port && tcp.state == netstat2::TcpState::Listen =>
+        {
+            socket.associated_pids.first().copied()
+        }
+        _ => None,
+    })
+}
+
+/// True when the process currently listening on `port` is the one we
+/// spawned (tracked in `SidecarHealth::owner_pid`), as opposed to some
+/// unrelated process that happens to be holding the same port.
+fn is_port_owned_by_our_sidecar(port: u16, owner_pid: Option<u32>) -> bool {
+    owner_pid_matches(find_pid_listening_on_port(port), owner_pid)
+}
+
+// Decision table behind `is_port_owned_by_our_sidecar`, split out so it can
+// be tested without a real socket table: only a known listener PID that
+// matches our own spawned PID counts as ownership.
+fn owner_pid_matches(listening_pid: Option<u32>, owner_pid: Option<u32>) -> bool {
+    match (listening_pid, owner_pid) {
+        (Some(listening_pid), Some(owner_pid)) => listening_pid == owner_pid,
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod port_ownership_tests {
+    use super::owner_pid_matches;
+
+    #[test]
+    fn matches_when_listening_pid_equals_owner_pid() {
+        assert!(owner_pid_matches(Some(42), Some(42)));
+    }
+
+    #[test]
+    fn does_not_match_a_different_pid() {
+        assert!(!owner_pid_matches(Some(42), Some(7)));
+    }
+
+    #[test]
+    fn does_not_match_when_nothing_is_listening() {
+        assert!(!owner_pid_matches(None, Some(42)));
+    }
+
+    #[test]
+    fn does_not_match_when_we_have_not_spawned_anything_yet() {
+        assert!(!owner_pid_matches(Some(42), None));
+    }
+}
+
 // Function to wait for the sidecar to be ready
 async fn wait_for_sidecar_ready(port: u16, timeout_seconds: u64) -> Result<(), String> {
     let start_time = std::time::Instant::now();
     let timeout = Duration::from_secs(timeout_seconds);
-    
+
     info!("Waiting for sidecar to be ready on port {}...", port);
-    
+
     while start_time.elapsed() < timeout {
         if is_port_in_use(port) {
             info!("Sidecar is ready on port {}", port);
             return Ok(());
         }
-        
+
         // Wait a bit before checking again
         tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
     }
-    
+
     Err(format!("Timeout waiting for sidecar to be ready on port {} after {} seconds", port, timeout_seconds))
 }
 
-// Fixed WebSocket port
-const WEBSOCKET_PORT: u16 = 9528;
+// Default WebSocket port, used unless overridden or already taken by
+// another (non-sidecar) process.
+const DEFAULT_WEBSOCKET_PORT: u16 = 9528;
 
-#[tauri::command]
-async fn start_websocket_sidecar(app: AppHandle) -> Result<u16, String> {
-    info!("start_websocket_sidecar called");
-    let websocket_state = app.state::<WebSocketState>();
-    
-    // Check if already running - do this in a separate scope to release the lock
-    {
-        let state_lock = websocket_state.0.lock().unwrap();
-        if state_lock.is_some() {
-            info!("WebSocket sidecar already running in this process");
-            return Ok(WEBSOCKET_PORT);
-        }
+// Preferred port from `TODOFORAI_WS_PORT` / `--ws-port <n>`, falling back to
+// `DEFAULT_WEBSOCKET_PORT`. This is a preference, not a guarantee:
+// `start_websocket_sidecar` falls back to an ephemeral port if it's held by
+// an unrelated process.
+fn preferred_websocket_port() -> u16 {
+    parse_preferred_port(env::var("TODOFORAI_WS_PORT").ok(), &env::args().collect::<Vec<_>>())
+        .unwrap_or(DEFAULT_WEBSOCKET_PORT)
+}
+
+// Split out of `preferred_websocket_port` so the env-var/CLI-arg precedence
+// can be tested without touching real process env/args.
+fn parse_preferred_port(env_value: Option<String>, args: &[String]) -> Option<u16> {
+    if let Some(port) = env_value.and_then(|v| v.parse().ok()) {
+        return Some(port);
     }
 
-    // Check if the port is already in use (possibly by another process)
-    if is_port_in_use(WEBSOCKET_PORT) {
-        info!(
-            "Port {} is already in use, assuming WebSocket sidecar is running",
-            WEBSOCKET_PORT
-        );
-        return Ok(WEBSOCKET_PORT);
+    args.iter()
+        .position(|a| a == "--ws-port")
+        .and_then(|idx| args.get(idx + 1))
+        .and_then(|v| v.parse().ok())
+}
+
+#[cfg(test)]
+mod preferred_port_tests {
+    use super::parse_preferred_port;
+
+    fn args(values: &[&str]) -> Vec<String> {
+        values.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn env_var_takes_precedence_over_cli_arg() {
+        let got = parse_preferred_port(Some("1234".to_string()), &args(&["--ws-port", "5678"]));
+        assert_eq!(got, Some(1234));
+    }
+
+    #[test]
+    fn falls_back_to_cli_arg_when_env_var_is_absent() {
+        let got = parse_preferred_port(None, &args(&["--ws-port", "5678"]));
+        assert_eq!(got, Some(5678));
+    }
+
+    #[test]
+    fn ignores_an_unparseable_env_var_and_falls_back_to_cli_arg() {
+        let got = parse_preferred_port(Some("not-a-port".to_string()), &args(&["--ws-port", "5678"]));
+        assert_eq!(got, Some(5678));
+    }
+
+    #[test]
+    fn returns_none_when_neither_source_is_set() {
+        assert_eq!(parse_preferred_port(None, &args(&[])), None);
+    }
+
+    #[test]
+    fn ignores_a_dangling_flag_with_no_value() {
+        assert_eq!(parse_preferred_port(None, &args(&["--ws-port"])), None);
     }
+}
 
-    // Check for force Python mode via environment variable
+/// Binds an OS-assigned free port and immediately releases it for the
+/// sidecar to bind in turn. Used when the preferred port is held by a
+/// process that isn't our own sidecar.
+fn find_ephemeral_port() -> Result<u16, String> {
+    std::net::TcpListener::bind(("127.0.0.1", 0))
+        .and_then(|listener| listener.local_addr())
+        .map(|addr| addr.port())
+        .map_err(|e| format!("Failed to find a free port: {}", e))
+}
+
+// Supervisor backoff/restart tuning
+const RESTART_BASE_DELAY_MS: u64 = 500;
+const RESTART_MAX_DELAY_MS: u64 = 30_000;
+const STABLE_WINDOW_SECS: u64 = 10;
+const MAX_RESTARTS: u32 = 5;
+const MAX_RESTARTS_WINDOW_SECS: u64 = 60;
+
+// Whether to run the raw Python script instead of the packaged sidecar
+// executable, and whether we're in dev mode (gates dev-only features like
+// hot reload).
+fn sidecar_mode_flags() -> (bool /* use_python */, bool /* is_dev_mode */) {
     let force_python = env::var("TODOFORAI_FORCE_PYTHON").unwrap_or_default() == "1";
     let force_production = env::var("TODOFORAI_FORCE_PRODUCTION").unwrap_or_default() == "1";
-    
-    // Determine if we're in development or production mode
+
     #[cfg(debug_assertions)]
     let is_dev_mode = true;
     #[cfg(not(debug_assertions))]
     let is_dev_mode = false;
-    
-    
-    // Override mode if force_python is set
+
     let use_python = (is_dev_mode && !force_production) || force_python;
+    (use_python, is_dev_mode)
+}
+
+// Resolves the bundled `ws_sidecar.py` path (the dev-mode entry point, and
+// the fallback if the packaged sidecar command can't be created).
+fn resolve_sidecar_script_path(app: &AppHandle) -> PathBuf {
+    app.path()
+        .resolve("resources/python/ws_sidecar.py", BaseDirectory::Resource)
+        .expect("Failed to resolve python script path")
+}
+
+// Spawns the Python/sidecar child, shared by the initial start and every
+// supervised restart.
+async fn spawn_sidecar_process(
+    app: &AppHandle,
+    port: u16,
+) -> Result<(tokio::sync::mpsc::Receiver<CommandEvent>, CommandChild), String> {
+    let (use_python, is_dev_mode) = sidecar_mode_flags();
 
     info!(
-        "Running in {} mode (is_dev_mode: {}, force_python: {}, force_production: {})",
+        "Running in {} mode (is_dev_mode: {})",
         if use_python { "Python script" } else { "sidecar executable" },
         is_dev_mode,
-        force_python,
-        force_production
     );
 
-    // Python script path (always available as a fallback)
-    let script_path = app
-        .path()
-        .resolve("resources/python/ws_sidecar.py", BaseDirectory::Resource)
-        .expect("Failed to resolve python script path");
-
-    let (mut rx, child) = if use_python {
-        // In development mode (or forced), use Python script
-        info!("Using Python script at: {:?}", script_path);
+    let script_path = resolve_sidecar_script_path(app);
 
-        let python_executable = if cfg!(target_os = "windows") {
-            "python"
-        } else {
-            "python3"
-        };
+    let python_executable = if cfg!(target_os = "windows") {
+        "python"
+    } else {
+        "python3"
+    };
 
+    let spawn_python = |app: &AppHandle| {
         app.shell()
             .command(python_executable)
             .args([
                 script_path.to_string_lossy().to_string(),
                 "--port".to_string(),
-                WEBSOCKET_PORT.to_string(),
+                port.to_string(),
             ])
             .env("PYTHONIOENCODING", "utf-8")
             .env("PYTHONUTF8", "1")
             .spawn()
-            .map_err(|e| format!("Failed to start Python script: {}", e))?
+    };
+
+    if use_python {
+        info!("Using Python script at: {:?}", script_path);
+        spawn_python(app).map_err(|e| format!("Failed to start Python script: {}", e))
     } else {
-        // In production mode, use the sidecar
         info!("Using sidecar executable: todoforai-edge-sidecar");
 
-        // Use the shell extension to get the sidecar
         match app.shell().sidecar("todoforai-edge-sidecar") {
             Ok(command) => {
                 info!("Sidecar command created successfully");
-
                 command
-                    .args(["--port", &WEBSOCKET_PORT.to_string()])
+                    .args(["--port", &port.to_string()])
                     .spawn()
-                    .map_err(|e| format!("Failed to spawn sidecar: {}", e))?
+                    .map_err(|e| format!("Failed to spawn sidecar: {}", e))
             }
             Err(e) => {
                 error!("Failed to create sidecar command: {}", e);
-                // Fall back to Python script
                 info!("Falling back to Python script at: {:?}", script_path);
+                spawn_python(app).map_err(|e| format!("Failed to start Python script fallback: {}", e))
+            }
+        }
+    }
+}
 
-                let python_executable = if cfg!(target_os = "windows") {
-                    "python"
-                } else {
-                    "python3"
-                };
-
-                app.shell()
-                    .command(python_executable)
-                    .args([
-                        script_path.to_string_lossy().to_string(),
-                        "--port".to_string(),
-                        WEBSOCKET_PORT.to_string(),
-                    ])
-                    .env("PYTHONIOENCODING", "utf-8")
-                    .env("PYTHONUTF8", "1")
-                    .spawn()
-                    .map_err(|e| format!("Failed to start Python script fallback: {}", e))?
+// Logs one stdout/stderr line locally, stores it in the ring buffer, and
+// forwards it to the webview as a `sidecar-log` event.
+fn capture_log_line(app: &AppHandle, websocket_state: &WebSocketState, stream: LogStream, line: &[u8]) {
+    let message = String::from_utf8_lossy(line).trim_end().to_string();
+    match stream {
+        LogStream::Stdout => println!("Py stdout: {}", message),
+        LogStream::Stderr => println!("Py stderr: {}", message),
+    }
+
+    let entry = SidecarLogLine {
+        stream,
+        level: match stream {
+            LogStream::Stdout => "info".to_string(),
+            LogStream::Stderr => "error".to_string(),
+        },
+        message,
+        timestamp_ms: now_ms(),
+    };
+
+    {
+        let mut buf = websocket_state.logs.lock().unwrap();
+        if buf.len() >= LOG_BUFFER_CAPACITY {
+            buf.pop_front();
+        }
+        buf.push_back(entry.clone());
+    }
+
+    let _ = app.emit("sidecar-log", entry);
+}
+
+// Drains stdout/stderr until the sidecar terminates or the channel closes;
+// a closed channel with no Terminated event counts as an unexpected exit.
+async fn pump_sidecar_events(
+    app: &AppHandle,
+    websocket_state: &WebSocketState,
+    mut rx: tokio::sync::mpsc::Receiver<CommandEvent>,
+) -> Option<i32> {
+    while let Some(event) = rx.recv().await {
+        match event {
+            CommandEvent::Stdout(line) => {
+                capture_log_line(app, websocket_state, LogStream::Stdout, &line);
+            }
+            CommandEvent::Stderr(line) => {
+                capture_log_line(app, websocket_state, LogStream::Stderr, &line);
             }
+            CommandEvent::Terminated(payload) => {
+                return payload.code;
+            }
+            _ => {}
+        }
+    }
+    // Channel closed without a Terminated event: treat as an unexpected exit.
+    None
+}
+
+// How long to coalesce a burst of filesystem events before acting on them.
+const HOT_RELOAD_DEBOUNCE_MS: u64 = 200;
+
+// On by default in a debug build, or opt-in via `TODOFORAI_HOT_RELOAD=1`.
+fn hot_reload_enabled(is_dev_mode: bool) -> bool {
+    is_dev_mode || env::var("TODOFORAI_HOT_RELOAD").unwrap_or_default() == "1"
+}
+
+/// True if any of the changed paths in `event` match `script_path` (editors
+/// often write through a temp file and rename it into place, so we match on
+/// the final path rather than the raw event kind).
+fn event_touches_script(event: &notify::Event, script_path: &std::path::Path) -> bool {
+    paths_contain(&event.paths, script_path)
+}
+
+// Split out of `event_touches_script` so the path-matching rule can be
+// tested without constructing a real `notify::Event`.
+fn paths_contain(paths: &[PathBuf], script_path: &std::path::Path) -> bool {
+    paths.iter().any(|p| p.as_path() == script_path)
+}
+
+#[cfg(test)]
+mod hot_reload_tests {
+    use super::paths_contain;
+    use std::path::PathBuf;
+
+    #[test]
+    fn matches_the_script_path_directly() {
+        let script = PathBuf::from("/tmp/ws_sidecar.py");
+        assert!(paths_contain(&[script.clone()], &script));
+    }
+
+    #[test]
+    fn matches_when_the_script_is_one_of_several_changed_paths() {
+        let script = PathBuf::from("/tmp/ws_sidecar.py");
+        let other = PathBuf::from("/tmp/unrelated.py");
+        assert!(paths_contain(&[other, script.clone()], &script));
+    }
+
+    #[test]
+    fn ignores_unrelated_paths() {
+        let script = PathBuf::from("/tmp/ws_sidecar.py");
+        let other = PathBuf::from("/tmp/unrelated.py");
+        assert!(!paths_contain(&[other], &script));
+    }
+}
+
+/// Watches `ws_sidecar.py`'s directory and triggers a debounced hot-reload
+/// restart on change, skipping bursts while a previous restart is in flight.
+fn spawn_hot_reload_watcher(app: AppHandle, script_path: PathBuf, port: u16) {
+    let watch_dir = script_path
+        .parent()
+        .map(|p| p.to_path_buf())
+        .unwrap_or_else(|| script_path.clone());
+
+    let (tx, rx) = std::sync::mpsc::channel();
+    let mut watcher = match notify::recommended_watcher(move |res: notify::Result<notify::Event>| {
+        let _ = tx.send(res);
+    }) {
+        Ok(watcher) => watcher,
+        Err(e) => {
+            error!("Failed to create sidecar hot-reload watcher: {}", e);
+            return;
         }
     };
 
-    // Handle stdout/stderr in a separate thread for both cases
-    tauri::async_runtime::spawn(async move {
-        while let Some(event) = rx.recv().await {
-            match event {
-                CommandEvent::Stdout(line) => {
-                    println!(
-                        "Py stdout: {}",
-                        String::from_utf8_lossy(&line).trim_end()
-                    );
-                }
-                CommandEvent::Stderr(line) => {
-                    println!(
-                        "Py stderr: {}",
-                        String::from_utf8_lossy(&line).trim_end()
-                    );
-                }
-                _ => {}
+    if let Err(e) = notify::Watcher::watch(&mut watcher, &watch_dir, notify::RecursiveMode::NonRecursive) {
+        error!("Failed to watch {:?} for hot reload: {}", watch_dir, e);
+        return;
+    }
+
+    info!("Watching {:?} for sidecar hot reload (port {})", watch_dir, port);
+
+    std::thread::spawn(move || {
+        // Keep the watcher alive for as long as this thread runs.
+        let _watcher = watcher;
+
+        while let Ok(Ok(event)) = rx.recv() {
+            if !event_touches_script(&event, &script_path) {
+                continue;
+            }
+
+            // Debounce: swallow any further events from the same save burst.
+            while rx.recv_timeout(Duration::from_millis(HOT_RELOAD_DEBOUNCE_MS)).is_ok() {}
+
+            let websocket_state = app.state::<WebSocketState>();
+            if websocket_state
+                .hot_reload_in_progress
+                .swap(true, std::sync::atomic::Ordering::SeqCst)
+            {
+                // A previous burst's restart hasn't finished yet; skip this one.
+                continue;
             }
+
+            info!("ws_sidecar.py changed, triggering hot reload on port {}", port);
+            websocket_state.restart_signal.notify_one();
         }
     });
+}
+
+// Supervises the sidecar for the lifetime of the app: restarts it on crash
+// with exponential backoff + jitter, and gives up after too many restarts in
+// a short window.
+async fn supervise_sidecar(app: AppHandle, port: u16) {
+    let websocket_state = app.state::<WebSocketState>();
+    let mut delay_ms = RESTART_BASE_DELAY_MS;
+    let mut restarts_in_window: Vec<std::time::Instant> = Vec::new();
+
+    loop {
+        if websocket_state.shutdown_requested.load(std::sync::atomic::Ordering::SeqCst) {
+            info!("Shutdown requested; supervisor for port {} exiting", port);
+            return;
+        }
+
+        let (rx, child) = match spawn_sidecar_process(&app, port).await {
+            Ok(pair) => pair,
+            Err(e) => {
+                error!("Supervisor failed to spawn sidecar: {}", e);
+                let mut health = websocket_state.health.lock().unwrap();
+                health.running = false;
+                health.fatal_error = Some(e);
+                return;
+            }
+        };
+
+        if wait_for_sidecar_ready(port, 10).await.is_err() {
+            warn!("Sidecar did not become ready on port {} in time", port);
+        }
+
+        {
+            let pid = child.pid();
+            let mut sidecar_lock = websocket_state.sidecar.lock().unwrap();
+            *sidecar_lock = Some(WebSocketSidecar { child: Some(child) });
+            let mut health = websocket_state.health.lock().unwrap();
+            health.running = true;
+            health.owner_pid = Some(pid);
+        }
+
+        let started_at = std::time::Instant::now();
+        let (exit_code, hot_reload) = {
+            let pump = pump_sidecar_events(&app, &websocket_state, rx);
+            tokio::pin!(pump);
+            let mut hot_reload = false;
+            let code = loop {
+                tokio::select! {
+                    code = &mut pump => break code,
+                    _ = websocket_state.restart_signal.notified() => {
+                        info!("Hot reload requested, restarting sidecar on port {}", port);
+                        hot_reload = true;
+                        if let Some(sidecar) = websocket_state.sidecar.lock().unwrap().as_mut() {
+                            if let Some(child) = sidecar.child.take() {
+                                let _ = child.kill();
+                            }
+                        }
+                        // Loop back and let `pump` observe the resulting exit.
+                    }
+                    _ = websocket_state.shutdown_signal.notified() => {
+                        info!("Shutdown requested; supervisor for port {} exiting without restart", port);
+                        let mut health = websocket_state.health.lock().unwrap();
+                        health.running = false;
+                        drop(health);
+                        // Whether or not a hot-reload restart raced with the
+                        // shutdown, the flag must not stay stuck at `true` for
+                        // a future supervisor run on this state.
+                        websocket_state
+                            .hot_reload_in_progress
+                            .store(false, std::sync::atomic::Ordering::SeqCst);
+                        return;
+                    }
+                }
+            };
+            (code, hot_reload)
+        };
+
+        // The sidecar is gone either way; drop our handle to it.
+        {
+            let mut sidecar_lock = websocket_state.sidecar.lock().unwrap();
+            if let Some(mut sidecar) = sidecar_lock.take() {
+                sidecar.child = None; // already exited, nothing to kill
+            }
+        }
+
+        info!(
+            "Sidecar on port {} exited (code: {:?}, hot_reload: {}) after {:?}",
+            port, exit_code, hot_reload, started_at.elapsed()
+        );
 
-    // Wait for the sidecar to be ready (up to 10 seconds)
-    wait_for_sidecar_ready(WEBSOCKET_PORT, 10).await?;
+        {
+            let mut health = websocket_state.health.lock().unwrap();
+            health.running = false;
+            health.last_exit_code = exit_code;
+        }
+
+        if websocket_state.shutdown_requested.load(std::sync::atomic::Ordering::SeqCst) {
+            info!("Sidecar on port {} stopped intentionally; supervisor exiting", port);
+            return;
+        }
+
+        if hot_reload {
+            // Intentional restart, not a crash: reset backoff, skip the
+            // restart-count/fatal-error bookkeeping, and restart immediately.
+            delay_ms = RESTART_BASE_DELAY_MS;
+            websocket_state
+                .hot_reload_in_progress
+                .store(false, std::sync::atomic::Ordering::SeqCst);
+            continue;
+        }
+
+        // A sidecar that stayed up for a while resets the backoff delay to its
+        // base; the restart history used for the give-up cap is kept.
+        if started_at.elapsed() >= Duration::from_secs(STABLE_WINDOW_SECS) {
+            delay_ms = RESTART_BASE_DELAY_MS;
+        }
+
+        let now = std::time::Instant::now();
+        record_restart(&mut restarts_in_window, now);
+
+        if restarts_in_window.len() as u32 > MAX_RESTARTS {
+            let msg = format!(
+                "Sidecar crashed {} times within {} seconds; giving up",
+                restarts_in_window.len(), MAX_RESTARTS_WINDOW_SECS
+            );
+            error!("{}", msg);
+            let mut health = websocket_state.health.lock().unwrap();
+            health.fatal_error = Some(msg.clone());
+            drop(health);
+            let _ = app.emit("sidecar-fatal-error", msg);
+            return;
+        }
+
+        {
+            let mut health = websocket_state.health.lock().unwrap();
+            health.restart_count += 1;
+        }
+
+        let jitter_ms = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.subsec_nanos() as u64 % 250)
+            .unwrap_or(0);
+        let wait = Duration::from_millis(delay_ms + jitter_ms);
+        warn!("Restarting sidecar on port {} in {:?}", port, wait);
+        tokio::time::sleep(wait).await;
+
+        delay_ms = next_backoff_delay(delay_ms);
+    }
+}
+
+// Drops restart timestamps older than `MAX_RESTARTS_WINDOW_SECS`, then
+// records `now` as a new one.
+fn record_restart(restarts_in_window: &mut Vec<std::time::Instant>, now: std::time::Instant) {
+    restarts_in_window.retain(|t| now.duration_since(*t) < Duration::from_secs(MAX_RESTARTS_WINDOW_SECS));
+    restarts_in_window.push(now);
+}
+
+// Doubles the restart delay, capped at `RESTART_MAX_DELAY_MS`.
+fn next_backoff_delay(delay_ms: u64) -> u64 {
+    (delay_ms * 2).min(RESTART_MAX_DELAY_MS)
+}
+
+#[cfg(test)]
+mod supervisor_tests {
+    use super::*;
+    use std::time::{Duration, Instant};
+
+    #[test]
+    fn backoff_doubles_and_caps() {
+        let mut delay = RESTART_BASE_DELAY_MS;
+        delay = next_backoff_delay(delay);
+        assert_eq!(delay, RESTART_BASE_DELAY_MS * 2);
+        for _ in 0..10 {
+            delay = next_backoff_delay(delay);
+        }
+        assert_eq!(delay, RESTART_MAX_DELAY_MS);
+    }
+
+    #[test]
+    fn record_restart_drops_entries_outside_the_window() {
+        let now = Instant::now();
+        let mut history = vec![now - Duration::from_secs(MAX_RESTARTS_WINDOW_SECS + 1)];
+        record_restart(&mut history, now);
+        // The stale entry is dropped, leaving only the new one.
+        assert_eq!(history.len(), 1);
+    }
+
+    #[test]
+    fn restarts_within_window_accumulate_towards_the_cap() {
+        let now = Instant::now();
+        let mut history = Vec::new();
+        for _ in 0..MAX_RESTARTS {
+            record_restart(&mut history, now);
+        }
+        assert_eq!(history.len() as u32, MAX_RESTARTS);
+        assert!(history.len() as u32 <= MAX_RESTARTS);
+
+        record_restart(&mut history, now);
+        assert!(history.len() as u32 > MAX_RESTARTS);
+    }
+}
+
+#[tauri::command]
+async fn start_websocket_sidecar(app: AppHandle) -> Result<u16, String> {
+    info!("start_websocket_sidecar called");
+    let websocket_state = app.state::<WebSocketState>();
+
+    // Check if already running - do this in a separate scope to release the lock
+    {
+        let state_lock = websocket_state.sidecar.lock().unwrap();
+        if state_lock.is_some() {
+            let port = *websocket_state.port.lock().unwrap();
+            info!("WebSocket sidecar already running in this process on port {}", port);
+            return Ok(port);
+        }
+    }
+
+    let preferred_port = preferred_websocket_port();
+
+    // Check if the port is already in use (possibly by another process)
+    let port = if is_port_in_use(preferred_port) {
+        let previous_owner = websocket_state.health.lock().unwrap().owner_pid;
+        if is_port_owned_by_our_sidecar(preferred_port, previous_owner) {
+            info!(
+                "Port {} is in use by our own previously-spawned sidecar (pid {:?})",
+                preferred_port, previous_owner
+            );
+            preferred_port
+        } else {
+            let ephemeral_port = find_ephemeral_port()?;
+            warn!(
+                "Preferred port {} is held by another process; falling back to ephemeral port {}",
+                preferred_port, ephemeral_port
+            );
+            ephemeral_port
+        }
+    } else {
+        preferred_port
+    };
 
-    // Create a sidecar wrapper and store it - do this in a separate scope
     {
-        let mut state_lock = websocket_state.0.lock().unwrap();
-        let sidecar = WebSocketSidecar { child: Some(child) };
-        *state_lock = Some(sidecar);
+        let mut health = websocket_state.health.lock().unwrap();
+        *health = SidecarHealth::default();
+    }
+    websocket_state.shutdown_requested.store(false, std::sync::atomic::Ordering::SeqCst);
+    *websocket_state.port.lock().unwrap() = port;
+
+    let app_for_supervisor = app.clone();
+    tauri::async_runtime::spawn(async move {
+        supervise_sidecar(app_for_supervisor, port).await;
+    });
+
+    let (_, is_dev_mode) = sidecar_mode_flags();
+    if hot_reload_enabled(is_dev_mode) {
+        spawn_hot_reload_watcher(app.clone(), resolve_sidecar_script_path(&app), port);
     }
 
-    info!("WebSocket sidecar started and ready on port {}", WEBSOCKET_PORT);
-    Ok(WEBSOCKET_PORT)
+    wait_for_sidecar_ready(port, 10).await?;
+
+    info!("WebSocket sidecar started and ready on port {}", port);
+    Ok(port)
+}
+
+#[tauri::command]
+fn get_websocket_port(app: AppHandle) -> u16 {
+    // Return the port actually bound by the sidecar, which may have been
+    // auto-negotiated away from the preferred one.
+    *app.state::<WebSocketState>().port.lock().unwrap()
+}
+
+#[tauri::command]
+fn get_sidecar_status(app: AppHandle) -> SidecarHealth {
+    let websocket_state = app.state::<WebSocketState>();
+    websocket_state.health.lock().unwrap().clone()
 }
 
+/// Returns the buffered sidecar log history so a newly opened window can
+/// backfill its console instead of only seeing lines emitted from now on.
 #[tauri::command]
-fn get_websocket_port() -> u16 {
-    // Always return the fixed port
-    WEBSOCKET_PORT
+fn get_sidecar_logs(app: AppHandle) -> Vec<SidecarLogLine> {
+    let websocket_state = app.state::<WebSocketState>();
+    websocket_state.logs.lock().unwrap().iter().cloned().collect()
 }
 
-// Cross-platform function to kill process on port
+// Cross-platform function to kill process on port, resolved via the OS
+// socket table instead of shelling out to lsof/netstat/taskkill.
 fn kill_process_on_port(port: u16) -> Result<(), String> {
     info!("Attempting to kill process on port {}", port);
 
-    #[cfg(target_os = "windows")]
-    {
-        // Windows: Use netstat and taskkill
-        let output = std::process::Command::new("netstat")
-            .args(["-ano"])
-            .output()
-            .map_err(|e| format!("Failed to run netstat: {}", e))?;
-
-        let output_str = String::from_utf8_lossy(&output.stdout);
-        for line in output_str.lines() {
-            if line.contains(&format!(":{}", port)) && line.contains("LISTENING") {
-                let parts: Vec<&str> = line.split_whitespace().collect();
-                if let Some(pid) = parts.last() {
-                    let _ = std::process::Command::new("taskkill")
-                        .args(["/F", "/PID", pid])
-                        .output();
-                    info!("Killed process with PID: {}", pid);
-                }
+    let Some(pid) = find_pid_listening_on_port(port) else {
+        info!("No process found listening on port {}", port);
+        return Ok(());
+    };
+
+    let mut system = sysinfo::System::new();
+    system.refresh_processes(sysinfo::ProcessesToUpdate::All, true);
+
+    match system.process(sysinfo::Pid::from_u32(pid)) {
+        Some(process) => {
+            if process.kill() {
+                info!("Killed process with PID: {}", pid);
+            } else {
+                warn!("Failed to signal process with PID: {} (already gone?)", pid);
             }
+            Ok(())
+        }
+        None => {
+            info!("PID {} from the socket table is already gone", pid);
+            Ok(())
         }
     }
+}
 
-    #[cfg(not(target_os = "windows"))]
-    {
-        // Unix-like: Use lsof and kill
-        let output = std::process::Command::new("lsof")
-            .args(["-t", &format!("-i:{}", port)])
-            .output();
-
-        if let Ok(output) = output {
-            let pids = String::from_utf8_lossy(&output.stdout);
-            for pid in pids.lines() {
-                if !pid.trim().is_empty() {
-                    let _ = std::process::Command::new("kill")
-                        .args(["-9", pid.trim()])
-                        .output();
-                    info!("Killed process with PID: {}", pid.trim());
-                }
-            }
+// Default grace period before a graceful shutdown escalates to a hard kill.
+const DEFAULT_SHUTDOWN_GRACE_MS: u64 = 5_000;
+
+// Sends SIGTERM to a process. Unlike `CommandChild::kill`, this gives the
+// sidecar a chance to handle the signal and shut down cleanly.
+#[cfg(not(target_os = "windows"))]
+fn request_graceful_exit(pid: u32) -> Result<(), String> {
+    nix::sys::signal::kill(nix::unistd::Pid::from_raw(pid as i32), nix::sys::signal::Signal::SIGTERM)
+        .map_err(|e| format!("Failed to send SIGTERM to pid {}: {}", pid, e))
+}
+
+// `GenerateConsoleCtrlEvent(CTRL_BREAK_EVENT, ...)` only reaches processes in
+// a console process group created with `CREATE_NEW_PROCESS_GROUP`, and
+// tauri_plugin_shell doesn't give us a way to spawn the sidecar with that
+// flag set. Rather than call it and have it fail silently every time, report
+// honestly that a soft shutdown isn't available so `graceful_shutdown` falls
+// back to a hard kill.
+#[cfg(target_os = "windows")]
+fn request_graceful_exit(_pid: u32) -> Result<(), String> {
+    Err("graceful shutdown is not supported on Windows for this sidecar".to_string())
+}
+
+// Asks the sidecar to exit gracefully, polls for the port to be released,
+// and escalates to a hard kill once `grace_period` elapses without it going
+// away.
+async fn graceful_shutdown(child: CommandChild, port: u16, grace_period: Duration) {
+    let pid = child.pid();
+
+    if let Err(e) = request_graceful_exit(pid) {
+        warn!("{}; falling back to a hard kill", e);
+        let _ = child.kill();
+        return;
+    }
+
+    let deadline = std::time::Instant::now() + grace_period;
+    while std::time::Instant::now() < deadline {
+        if !is_port_in_use(port) {
+            info!("Sidecar (pid {}) exited gracefully", pid);
+            return;
         }
+        tokio::time::sleep(Duration::from_millis(100)).await;
     }
 
-    Ok(())
+    warn!(
+        "Sidecar (pid {}) did not exit within the {:?} grace period, forcing kill",
+        pid, grace_period
+    );
+    let _ = child.kill();
 }
 
+/// Stops the supervised sidecar, waiting up to `grace_period_ms` (default
+/// `DEFAULT_SHUTDOWN_GRACE_MS`) for it to exit on its own before a hard kill.
+///
+/// On Windows this grace period is currently dead: `request_graceful_exit`
+/// has no way to deliver a soft-exit request without `CREATE_NEW_PROCESS_GROUP`
+/// (which `tauri_plugin_shell` doesn't set when spawning the sidecar), so
+/// `graceful_shutdown` falls straight through to a hard kill there regardless
+/// of `grace_period_ms`.
 #[tauri::command]
-fn stop_websocket_sidecar(app: AppHandle) -> Result<(), String> {
+async fn stop_websocket_sidecar(app: AppHandle, grace_period_ms: Option<u64>) -> Result<(), String> {
     let websocket_state = app.state::<WebSocketState>();
-    let mut state_lock = websocket_state.0.lock().unwrap();
+    let port = *websocket_state.port.lock().unwrap();
 
-    // Kill our managed process
-    if let Some(mut sidecar) = state_lock.take() {
-        if let Some(child) = sidecar.child.take() {
-            let _ = child.kill();
-            info!("Terminated managed WebSocket sidecar process");
-        }
+    // Tell the supervisor this exit is intentional before touching the
+    // child, so it returns instead of respawning a new sidecar on this port.
+    websocket_state.shutdown_requested.store(true, std::sync::atomic::Ordering::SeqCst);
+    websocket_state.shutdown_signal.notify_one();
+
+    // Take our managed child out of the shared state before awaiting so the
+    // mutex guard never has to live across an `.await`.
+    let managed_child = {
+        let mut state_lock = websocket_state.sidecar.lock().unwrap();
+        state_lock.take().and_then(|mut sidecar| sidecar.child.take())
+    };
+
+    if let Some(child) = managed_child {
+        let grace_period = Duration::from_millis(grace_period_ms.unwrap_or(DEFAULT_SHUTDOWN_GRACE_MS));
+        graceful_shutdown(child, port, grace_period).await;
+        info!("Terminated managed WebSocket sidecar process");
+    }
+
+    {
+        let mut health = websocket_state.health.lock().unwrap();
+        health.running = false;
     }
 
     // Also kill any process using the port (cleanup orphaned processes)
-    kill_process_on_port(WEBSOCKET_PORT)?;
+    kill_process_on_port(port)?;
 
     info!("WebSocket sidecar cleanup completed");
     Ok(())
@@ -408,7 +1033,16 @@ pub fn run() {
         .plugin(tauri_plugin_deep_link::init())
         .plugin(tauri_plugin_os::init())
         .plugin(tauri_plugin_shell::init())
-        .manage(WebSocketState(Arc::new(Mutex::new(None))))
+        .manage(WebSocketState {
+            sidecar: Arc::new(Mutex::new(None)),
+            health: Arc::new(Mutex::new(SidecarHealth::default())),
+            logs: Arc::new(Mutex::new(VecDeque::with_capacity(LOG_BUFFER_CAPACITY))),
+            restart_signal: Arc::new(tokio::sync::Notify::new()),
+            hot_reload_in_progress: Arc::new(std::sync::atomic::AtomicBool::new(false)),
+            shutdown_requested: Arc::new(std::sync::atomic::AtomicBool::new(false)),
+            shutdown_signal: Arc::new(tokio::sync::Notify::new()),
+            port: Arc::new(Mutex::new(DEFAULT_WEBSOCKET_PORT)),
+        })
         .setup(|app| {
             // Set up logging with tauri_plugin_log
             app.handle().plugin(
@@ -421,7 +1055,7 @@ pub fn run() {
             // Debug: Print all CLI args
             let args: Vec<String> = std::env::args().collect();
             info!("🔍 CLI ARGS: {:?}", args);
-            
+
             // Just log deep link detection for debugging
             for arg in &args {
                 if arg.starts_with("todoforai-edge://auth/apikey/") {
@@ -444,10 +1078,25 @@ pub fn run() {
             // Add window close event handler for cleanup
             if let Some(main_window) = app.get_webview_window("main") {
                 let app_handle = app.handle().clone();
+                let window_to_close = main_window.clone();
                 main_window.on_window_event(move |event| {
-                    if let tauri::WindowEvent::CloseRequested { .. } = event {
-                        info!("Window close requested, cleaning up WebSocket sidecar");
-                        let _ = stop_websocket_sidecar(app_handle.clone());
+                    if let tauri::WindowEvent::CloseRequested { api, .. } = event {
+                        // Hold the window open until the sidecar is actually
+                        // stopped, so the grace-period/hard-kill escalation
+                        // in stop_websocket_sidecar gets to run to completion
+                        // instead of racing the app's exit.
+                        api.prevent_close();
+                        info!("Window close requested, cleaning up WebSocket sidecar before exit");
+                        let app_handle = app_handle.clone();
+                        let window_to_close = window_to_close.clone();
+                        tauri::async_runtime::spawn(async move {
+                            let _ = stop_websocket_sidecar(app_handle, None).await;
+                            // `destroy` rather than `close`: `close` would emit
+                            // CloseRequested again and land back in this handler.
+                            if let Err(e) = window_to_close.destroy() {
+                                error!("Failed to close main window after sidecar cleanup: {}", e);
+                            }
+                        });
                     }
                 });
             }
@@ -470,7 +1119,9 @@ pub fn run() {
             get_env_var,
             start_websocket_sidecar,
             stop_websocket_sidecar,
-            get_websocket_port
+            get_websocket_port,
+            get_sidecar_status,
+            get_sidecar_logs
         ])
         .run(tauri::generate_context!())
         .expect("error while running Tauri application");